@@ -118,6 +118,125 @@ impl Fundamentals for PcSet {
     }
 }
 
+/// A bitset-backed pitch-class set, packing membership of pitch-classes
+/// 0..11 into bits 0..11 of a `u16`. Transforms are O(1) bitwise
+/// operations instead of the element-wise `Vec` walks used by `PcSet`,
+/// which makes batch chroma-domain work (e.g. generating all Tn/TnI
+/// forms) allocation-free.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChromaSet(pub u16);
+
+impl ChromaSet {
+    /// Returns the transposition of the chroma set by _n_ semitones, as a
+    /// 12-bit barrel rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let chromaset = ChromaSet(0b0000_0000_1110);
+    /// assert_eq!(chromaset.transpose(4), ChromaSet(0b0000_1110_0000));
+    ///
+    /// ```
+    pub fn transpose(&self, n: i8) -> ChromaSet {
+        let n = (((n % 12) + 12) % 12) as u32;
+        let x = (self.0 & 0x0FFF) as u32;
+        ChromaSet((((x << n) | (x >> (12 - n))) & 0x0FFF) as u16)
+    }
+    /// Returns the inversion of the chroma set, a 12-bit reversal of the
+    /// bit order around pitch-class 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let chromaset = ChromaSet(0b0000_0000_0110);
+    /// assert_eq!(chromaset.invert(), ChromaSet(0b1100_0000_0000));
+    ///
+    /// ```
+    pub fn invert(&self) -> ChromaSet {
+        let x = self.0 & 0x0FFF;
+        let reversed = (1..12).fold(x & 1, |acc, i| {
+            if x & (1 << i) != 0 { acc | (1 << (12 - i)) } else { acc }
+        });
+        ChromaSet(reversed)
+    }
+    /// Returns the complement of the chroma set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let chromaset = ChromaSet(0b0000_0000_0001);
+    /// assert_eq!(chromaset.complement(), ChromaSet(0b1111_1111_1110));
+    ///
+    /// ```
+    pub fn complement(&self) -> ChromaSet {
+        ChromaSet((!self.0) & 0x0FFF)
+    }
+    /// Returns the union of two chroma sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let a = ChromaSet(0b0000_0000_0011);
+    /// let b = ChromaSet(0b0000_0000_0110);
+    /// assert_eq!(a.union(&b), ChromaSet(0b0000_0000_0111));
+    ///
+    /// ```
+    pub fn union(&self, other: &ChromaSet) -> ChromaSet {
+        ChromaSet(self.0 | other.0)
+    }
+    /// Returns the intersection of two chroma sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let a = ChromaSet(0b0000_0000_0011);
+    /// let b = ChromaSet(0b0000_0000_0110);
+    /// assert_eq!(a.intersection(&b), ChromaSet(0b0000_0000_0010));
+    ///
+    /// ```
+    pub fn intersection(&self, other: &ChromaSet) -> ChromaSet {
+        ChromaSet(self.0 & other.0)
+    }
+    /// Returns the difference of two chroma sets (the elements of `self`
+    /// that are not in `other`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let a = ChromaSet(0b0000_0000_0011);
+    /// let b = ChromaSet(0b0000_0000_0110);
+    /// assert_eq!(a.difference(&b), ChromaSet(0b0000_0000_0001));
+    ///
+    /// ```
+    pub fn difference(&self, other: &ChromaSet) -> ChromaSet {
+        ChromaSet(self.0 & !other.0)
+    }
+}
+
+impl From<PcSet> for ChromaSet {
+    fn from(pcset: PcSet) -> ChromaSet {
+        ChromaSet(pcset.chroma())
+    }
+}
+
+impl From<ChromaSet> for PcSet {
+    fn from(chromaset: ChromaSet) -> PcSet {
+        (0..12).filter(|x| chromaset.0 & (1 << x) != 0).collect()
+    }
+}
+
 pub trait SetOperations {
     /// Returns the complement of the pitch-class set.
     ///
@@ -351,6 +470,149 @@ impl SetOperations for PcSet {
     }
 }
 
+fn normalized(pcset: &PcSet) -> PcSet {
+    let mut reduced: PcSet = pcset.iter().map(|x| ((x % 12) + 12) % 12).collect();
+    reduced = reduced.sort();
+    reduced.dedup();
+    reduced
+}
+
+pub trait SetRelations {
+    /// Returns the union of two pitch-class sets, normalized (deduped,
+    /// reduced modulo 12) and returned in `normal()` order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let pcset: PcSet = vec![0,1,2];
+    /// let other: PcSet = vec![2,3,4];
+    /// assert_eq!(pcset.union(&other), vec![0,1,2,3,4]);
+    ///
+    /// ```
+    fn union(&self, other: &Self) -> Self;
+    /// Returns the intersection of two pitch-class sets, normalized and
+    /// returned in `normal()` order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let pcset: PcSet = vec![0,1,2];
+    /// let other: PcSet = vec![2,3,4];
+    /// assert_eq!(pcset.intersection(&other), vec![2]);
+    ///
+    /// ```
+    fn intersection(&self, other: &Self) -> Self;
+    /// Returns the elements of `self` that are not in `other`, normalized
+    /// and returned in `normal()` order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let pcset: PcSet = vec![0,1,2];
+    /// let other: PcSet = vec![2,3,4];
+    /// assert_eq!(pcset.difference(&other), vec![0,1]);
+    ///
+    /// ```
+    fn difference(&self, other: &Self) -> Self;
+    /// Returns the elements in exactly one of the two pitch-class sets,
+    /// normalized and returned in `normal()` order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let pcset: PcSet = vec![0,1,2];
+    /// let other: PcSet = vec![2,3,4];
+    /// assert_eq!(pcset.symmetric_difference(&other), vec![0,1,3,4]);
+    ///
+    /// ```
+    fn symmetric_difference(&self, other: &Self) -> Self;
+    /// Returns true if every pitch-class in `self` is also in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let pcset: PcSet = vec![0,1];
+    /// let other: PcSet = vec![0,1,2];
+    /// assert!(pcset.is_subset(&other));
+    ///
+    /// ```
+    fn is_subset(&self, other: &Self) -> bool;
+    /// Returns true if every pitch-class in `other` is also in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let pcset: PcSet = vec![0,1,2];
+    /// let other: PcSet = vec![0,1];
+    /// assert!(pcset.is_superset(&other));
+    ///
+    /// ```
+    fn is_superset(&self, other: &Self) -> bool;
+    /// Returns true if the two pitch-class sets share no pitch-classes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let pcset: PcSet = vec![0,1,2];
+    /// let other: PcSet = vec![3,4,5];
+    /// assert!(pcset.is_disjoint(&other));
+    ///
+    /// ```
+    fn is_disjoint(&self, other: &Self) -> bool;
+}
+
+impl SetRelations for PcSet {
+    fn union(&self, other: &PcSet) -> PcSet {
+        let mut combined = normalized(self);
+        combined.extend(normalized(other));
+        combined = combined.sort();
+        combined.dedup();
+        combined.normal()
+    }
+    fn intersection(&self, other: &PcSet) -> PcSet {
+        let a = normalized(self);
+        let b = normalized(other);
+        a.iter().filter(|x| b.contains(x)).cloned().collect::<PcSet>().normal()
+    }
+    fn difference(&self, other: &PcSet) -> PcSet {
+        let a = normalized(self);
+        let b = normalized(other);
+        a.iter().filter(|x| !b.contains(x)).cloned().collect::<PcSet>().normal()
+    }
+    fn symmetric_difference(&self, other: &PcSet) -> PcSet {
+        let mut combined = self.difference(other);
+        combined.extend(other.difference(self));
+        combined = combined.sort();
+        combined.dedup();
+        combined.normal()
+    }
+    fn is_subset(&self, other: &PcSet) -> bool {
+        let a = normalized(self);
+        let b = normalized(other);
+        a.iter().all(|x| b.contains(x))
+    }
+    fn is_superset(&self, other: &PcSet) -> bool {
+        other.is_subset(self)
+    }
+    fn is_disjoint(&self, other: &PcSet) -> bool {
+        self.intersection(other).is_empty()
+    }
+}
+
 pub trait SetAnalysis {
     /// Returns the interval-class vector of the pitch-class set.
     ///
@@ -431,12 +693,428 @@ impl SetAnalysis for PcSet {
     }
 }
 
+
+struct ForteClass {
+    name: &'static str,
+    prime: &'static [i8],
+}
+
+static SET_CLASSES: &[ForteClass] = &[
+    ForteClass { name: "0-1", prime: &[] },
+    ForteClass { name: "1-1", prime: &[0] },
+    ForteClass { name: "2-1", prime: &[0, 1] },
+    ForteClass { name: "2-2", prime: &[0, 2] },
+    ForteClass { name: "2-3", prime: &[0, 3] },
+    ForteClass { name: "2-4", prime: &[0, 4] },
+    ForteClass { name: "2-5", prime: &[0, 5] },
+    ForteClass { name: "2-6", prime: &[0, 6] },
+    ForteClass { name: "3-1", prime: &[0, 1, 2] },
+    ForteClass { name: "3-2", prime: &[0, 1, 3] },
+    ForteClass { name: "3-3", prime: &[0, 1, 4] },
+    ForteClass { name: "3-4", prime: &[0, 1, 5] },
+    ForteClass { name: "3-5", prime: &[0, 1, 6] },
+    ForteClass { name: "3-6", prime: &[0, 2, 4] },
+    ForteClass { name: "3-7", prime: &[0, 2, 5] },
+    ForteClass { name: "3-8", prime: &[0, 2, 6] },
+    ForteClass { name: "3-9", prime: &[0, 2, 7] },
+    ForteClass { name: "3-10", prime: &[0, 3, 6] },
+    ForteClass { name: "3-11", prime: &[0, 3, 7] },
+    ForteClass { name: "3-12", prime: &[0, 4, 8] },
+    ForteClass { name: "4-1", prime: &[0, 1, 2, 3] },
+    ForteClass { name: "4-2", prime: &[0, 1, 2, 4] },
+    ForteClass { name: "4-3", prime: &[0, 1, 3, 4] },
+    ForteClass { name: "4-4", prime: &[0, 1, 2, 5] },
+    ForteClass { name: "4-5", prime: &[0, 1, 2, 6] },
+    ForteClass { name: "4-6", prime: &[0, 1, 2, 7] },
+    ForteClass { name: "4-7", prime: &[0, 1, 4, 5] },
+    ForteClass { name: "4-8", prime: &[0, 1, 5, 6] },
+    ForteClass { name: "4-9", prime: &[0, 1, 6, 7] },
+    ForteClass { name: "4-10", prime: &[0, 2, 3, 5] },
+    ForteClass { name: "4-11", prime: &[0, 1, 3, 5] },
+    ForteClass { name: "4-12", prime: &[0, 2, 3, 6] },
+    ForteClass { name: "4-13", prime: &[0, 1, 3, 6] },
+    ForteClass { name: "4-14", prime: &[0, 2, 3, 7] },
+    ForteClass { name: "4-Z15", prime: &[0, 1, 4, 6] },
+    ForteClass { name: "4-16", prime: &[0, 1, 5, 7] },
+    ForteClass { name: "4-17", prime: &[0, 3, 4, 7] },
+    ForteClass { name: "4-18", prime: &[0, 1, 4, 7] },
+    ForteClass { name: "4-19", prime: &[0, 1, 4, 8] },
+    ForteClass { name: "4-20", prime: &[0, 1, 5, 8] },
+    ForteClass { name: "4-21", prime: &[0, 2, 4, 6] },
+    ForteClass { name: "4-22", prime: &[0, 2, 4, 7] },
+    ForteClass { name: "4-23", prime: &[0, 2, 5, 7] },
+    ForteClass { name: "4-24", prime: &[0, 2, 4, 8] },
+    ForteClass { name: "4-25", prime: &[0, 2, 6, 8] },
+    ForteClass { name: "4-26", prime: &[0, 3, 5, 8] },
+    ForteClass { name: "4-27", prime: &[0, 2, 5, 8] },
+    ForteClass { name: "4-28", prime: &[0, 3, 6, 9] },
+    ForteClass { name: "4-Z29", prime: &[0, 1, 3, 7] },
+    ForteClass { name: "5-1", prime: &[0, 1, 2, 3, 4] },
+    ForteClass { name: "5-2", prime: &[0, 1, 2, 3, 5] },
+    ForteClass { name: "5-3", prime: &[0, 1, 2, 4, 5] },
+    ForteClass { name: "5-4", prime: &[0, 1, 2, 3, 6] },
+    ForteClass { name: "5-5", prime: &[0, 1, 2, 3, 7] },
+    ForteClass { name: "5-6", prime: &[0, 1, 2, 5, 6] },
+    ForteClass { name: "5-7", prime: &[0, 1, 2, 6, 7] },
+    ForteClass { name: "5-8", prime: &[0, 2, 3, 4, 6] },
+    ForteClass { name: "5-9", prime: &[0, 1, 2, 4, 6] },
+    ForteClass { name: "5-10", prime: &[0, 1, 3, 4, 6] },
+    ForteClass { name: "5-11", prime: &[0, 2, 3, 4, 7] },
+    ForteClass { name: "5-Z12", prime: &[0, 1, 3, 5, 6] },
+    ForteClass { name: "5-13", prime: &[0, 1, 2, 4, 8] },
+    ForteClass { name: "5-14", prime: &[0, 1, 2, 5, 7] },
+    ForteClass { name: "5-15", prime: &[0, 1, 2, 6, 8] },
+    ForteClass { name: "5-16", prime: &[0, 1, 3, 4, 7] },
+    ForteClass { name: "5-Z17", prime: &[0, 1, 3, 4, 8] },
+    ForteClass { name: "5-Z18", prime: &[0, 1, 4, 5, 7] },
+    ForteClass { name: "5-19", prime: &[0, 1, 3, 6, 7] },
+    ForteClass { name: "5-20", prime: &[0, 1, 5, 6, 8] },
+    ForteClass { name: "5-21", prime: &[0, 1, 4, 5, 8] },
+    ForteClass { name: "5-22", prime: &[0, 1, 4, 7, 8] },
+    ForteClass { name: "5-23", prime: &[0, 2, 3, 5, 7] },
+    ForteClass { name: "5-24", prime: &[0, 1, 3, 5, 7] },
+    ForteClass { name: "5-25", prime: &[0, 2, 3, 5, 8] },
+    ForteClass { name: "5-26", prime: &[0, 2, 4, 5, 8] },
+    ForteClass { name: "5-27", prime: &[0, 1, 3, 5, 8] },
+    ForteClass { name: "5-28", prime: &[0, 2, 3, 6, 8] },
+    ForteClass { name: "5-29", prime: &[0, 1, 3, 6, 8] },
+    ForteClass { name: "5-30", prime: &[0, 1, 4, 6, 8] },
+    ForteClass { name: "5-31", prime: &[0, 1, 3, 6, 9] },
+    ForteClass { name: "5-32", prime: &[0, 1, 4, 6, 9] },
+    ForteClass { name: "5-33", prime: &[0, 2, 4, 6, 8] },
+    ForteClass { name: "5-34", prime: &[0, 2, 4, 6, 9] },
+    ForteClass { name: "5-35", prime: &[0, 2, 4, 7, 9] },
+    ForteClass { name: "5-Z36", prime: &[0, 1, 2, 4, 7] },
+    ForteClass { name: "5-Z37", prime: &[0, 3, 4, 5, 8] },
+    ForteClass { name: "5-Z38", prime: &[0, 1, 2, 5, 8] },
+    ForteClass { name: "6-1", prime: &[0, 1, 2, 3, 4, 5] },
+    ForteClass { name: "6-2", prime: &[0, 1, 2, 3, 4, 6] },
+    ForteClass { name: "6-Z3", prime: &[0, 1, 2, 3, 5, 6] },
+    ForteClass { name: "6-Z36", prime: &[0, 1, 2, 3, 4, 7] },
+    ForteClass { name: "6-Z4", prime: &[0, 1, 2, 4, 5, 6] },
+    ForteClass { name: "6-Z37", prime: &[0, 1, 2, 3, 4, 8] },
+    ForteClass { name: "6-5", prime: &[0, 1, 2, 3, 6, 7] },
+    ForteClass { name: "6-Z6", prime: &[0, 1, 2, 5, 6, 7] },
+    ForteClass { name: "6-Z38", prime: &[0, 1, 2, 3, 7, 8] },
+    ForteClass { name: "6-7", prime: &[0, 1, 2, 6, 7, 8] },
+    ForteClass { name: "6-8", prime: &[0, 2, 3, 4, 5, 7] },
+    ForteClass { name: "6-9", prime: &[0, 1, 2, 3, 5, 7] },
+    ForteClass { name: "6-Z10", prime: &[0, 1, 3, 4, 5, 7] },
+    ForteClass { name: "6-Z39", prime: &[0, 2, 3, 4, 5, 8] },
+    ForteClass { name: "6-Z11", prime: &[0, 1, 2, 4, 5, 7] },
+    ForteClass { name: "6-Z40", prime: &[0, 1, 2, 3, 5, 8] },
+    ForteClass { name: "6-Z12", prime: &[0, 1, 2, 4, 6, 7] },
+    ForteClass { name: "6-Z41", prime: &[0, 1, 2, 3, 6, 8] },
+    ForteClass { name: "6-Z13", prime: &[0, 1, 3, 4, 6, 7] },
+    ForteClass { name: "6-Z42", prime: &[0, 1, 2, 3, 6, 9] },
+    ForteClass { name: "6-14", prime: &[0, 1, 3, 4, 5, 8] },
+    ForteClass { name: "6-15", prime: &[0, 1, 2, 4, 5, 8] },
+    ForteClass { name: "6-16", prime: &[0, 1, 4, 5, 6, 8] },
+    ForteClass { name: "6-Z17", prime: &[0, 1, 2, 4, 7, 8] },
+    ForteClass { name: "6-Z43", prime: &[0, 1, 2, 5, 6, 8] },
+    ForteClass { name: "6-18", prime: &[0, 1, 2, 5, 7, 8] },
+    ForteClass { name: "6-Z19", prime: &[0, 1, 3, 4, 7, 8] },
+    ForteClass { name: "6-Z44", prime: &[0, 1, 2, 5, 6, 9] },
+    ForteClass { name: "6-20", prime: &[0, 1, 4, 5, 8, 9] },
+    ForteClass { name: "6-21", prime: &[0, 2, 3, 4, 6, 8] },
+    ForteClass { name: "6-22", prime: &[0, 1, 2, 4, 6, 8] },
+    ForteClass { name: "6-Z23", prime: &[0, 2, 3, 5, 6, 8] },
+    ForteClass { name: "6-Z45", prime: &[0, 2, 3, 4, 6, 9] },
+    ForteClass { name: "6-Z24", prime: &[0, 1, 3, 4, 6, 8] },
+    ForteClass { name: "6-Z46", prime: &[0, 1, 2, 4, 6, 9] },
+    ForteClass { name: "6-Z25", prime: &[0, 1, 3, 5, 6, 8] },
+    ForteClass { name: "6-Z47", prime: &[0, 1, 2, 4, 7, 9] },
+    ForteClass { name: "6-Z26", prime: &[0, 1, 3, 5, 7, 8] },
+    ForteClass { name: "6-Z48", prime: &[0, 1, 2, 5, 7, 9] },
+    ForteClass { name: "6-27", prime: &[0, 1, 3, 4, 6, 9] },
+    ForteClass { name: "6-Z28", prime: &[0, 1, 3, 5, 6, 9] },
+    ForteClass { name: "6-Z49", prime: &[0, 1, 3, 4, 7, 9] },
+    ForteClass { name: "6-Z29", prime: &[0, 2, 3, 6, 7, 9] },
+    ForteClass { name: "6-Z50", prime: &[0, 1, 4, 6, 7, 9] },
+    ForteClass { name: "6-30", prime: &[0, 1, 3, 6, 7, 9] },
+    ForteClass { name: "6-31", prime: &[0, 1, 4, 5, 7, 9] },
+    ForteClass { name: "6-32", prime: &[0, 2, 4, 5, 7, 9] },
+    ForteClass { name: "6-33", prime: &[0, 2, 3, 5, 7, 9] },
+    ForteClass { name: "6-34", prime: &[0, 1, 3, 5, 7, 9] },
+    ForteClass { name: "6-35", prime: &[0, 2, 4, 6, 8, 10] },
+];
+
+pub trait SetClass {
+    /// Returns the Forte number of the pitch-class set's set class, e.g.
+    /// `"3-1"` or `"5-Z17"`. Cardinalities 0 through 6 are looked up
+    /// directly by prime form; cardinalities 7 through 12 are named after
+    /// their complement, per Forte's original numbering convention.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let pcset: PcSet = vec![0,1,2];
+    /// assert_eq!(pcset.forte_name(), Some("3-1".to_string()));
+    ///
+    /// let augmented: PcSet = vec![0,4,8];
+    /// assert_eq!(augmented.forte_name(), Some("3-12".to_string()));
+    ///
+    /// ```
+    fn forte_name(&self) -> Option<String>;
+}
+
+impl SetClass for PcSet {
+    fn forte_name(&self) -> Option<String> {
+        let prime = self.prime();
+        let cardinality = prime.len();
+
+        if cardinality <= 6 {
+            SET_CLASSES
+                .iter()
+                .find(|class| class.prime == prime.as_slice())
+                .map(|class| class.name.to_string())
+        } else if cardinality <= 12 {
+            prime.complement().forte_name().map(|complement_name| {
+                let suffix = complement_name.split_once('-').map_or("", |(_, s)| s);
+                format!("{}-{}", cardinality, suffix)
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Looks up a pitch-class set class by its Forte number (e.g. `"3-1"` or
+/// `"5-Z17"`) and returns a representative prime form.
+///
+/// # Examples
+///
+/// ```
+/// use lipsi::*;
+///
+/// assert_eq!(from_forte("3-1"), Some(vec![0,1,2]));
+/// assert_eq!(from_forte("not-a-set-class"), None);
+///
+/// ```
+pub fn from_forte(name: &str) -> Option<PcSet> {
+    let (cardinality, suffix) = name.split_once('-')?;
+    let cardinality: usize = cardinality.parse().ok()?;
+
+    if cardinality <= 6 {
+        SET_CLASSES
+            .iter()
+            .find(|class| class.name == name)
+            .map(|class| class.prime.to_vec())
+    } else if cardinality <= 12 {
+        let complement_cardinality = 12 - cardinality;
+        from_forte(&format!("{}-{}", complement_cardinality, suffix))
+            .map(|representative| representative.complement().prime())
+    } else {
+        None
+    }
+}
+
+pub trait ZRelations {
+    /// Returns true if the two pitch-class sets share an identical
+    /// interval-class vector but are not Tn/TnI-equivalent (i.e. they have
+    /// different prime forms), the classic Z-relation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let pcset: PcSet = vec![0,1,4,6];
+    /// let other: PcSet = vec![0,1,3,7];
+    /// assert!(pcset.is_z_related(&other));
+    ///
+    /// ```
+    fn is_z_related(&self, other: &Self) -> bool;
+    /// Returns true if this set's prime form matches the prime form of
+    /// `other`'s complement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let pcset: PcSet = vec![0,1,2,3];
+    /// let other: PcSet = vec![0,1,2,3,4,5,6,7];
+    /// assert!(pcset.is_complement_related(&other));
+    ///
+    /// ```
+    fn is_complement_related(&self, other: &Self) -> bool;
+}
+
+impl ZRelations for PcSet {
+    fn is_z_related(&self, other: &PcSet) -> bool {
+        self.icvec() == other.icvec() && self.prime() != other.prime()
+    }
+    fn is_complement_related(&self, other: &PcSet) -> bool {
+        self.prime() == other.complement().prime()
+    }
+}
+
+/// An iterator over the distinct Tn/TnI forms of a pitch-class set,
+/// returned by `SetCombinatorics::orbit`. All forms are computed when
+/// `orbit` is called; iterating only walks the stored forms.
+pub struct Orbit {
+    forms: Vec<PcSet>,
+    index: usize,
+}
+
+impl Iterator for Orbit {
+    type Item = PcSet;
+
+    fn next(&mut self) -> Option<PcSet> {
+        let form = self.forms.get(self.index).cloned();
+        self.index += 1;
+        form
+    }
+}
+
+/// A lazy iterator over k-element combinations of a pitch-class set's
+/// elements, in combination order, returned by `SetCombinatorics::subsets`.
+pub struct Combinations {
+    items: PcSet,
+    indices: Vec<usize>,
+    done: bool,
+}
+
+impl Combinations {
+    fn new(items: PcSet, k: usize) -> Combinations {
+        let done = k > items.len();
+        Combinations { items, indices: (0..k).collect(), done }
+    }
+}
+
+impl Iterator for Combinations {
+    type Item = PcSet;
+
+    fn next(&mut self) -> Option<PcSet> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.indices.iter().map(|&i| self.items[i]).collect();
+
+        let n = self.items.len();
+        let k = self.indices.len();
+        self.done = true;
+        let mut i = k;
+        while i > 0 {
+            i -= 1;
+            if self.indices[i] < i + n - k {
+                self.indices[i] += 1;
+                for j in (i + 1)..k {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+                self.done = false;
+                break;
+            }
+        }
+
+        Some(result)
+    }
+}
+
+/// A lazy iterator over k-element supersets of a pitch-class set drawn
+/// from a given universe, returned by `SetCombinatorics::supersets_within`.
+pub struct Supersets {
+    base: PcSet,
+    combinations: Combinations,
+}
+
+impl Iterator for Supersets {
+    type Item = PcSet;
+
+    fn next(&mut self) -> Option<PcSet> {
+        self.combinations.next().map(|extra| {
+            let mut set = self.base.clone();
+            set.extend(extra);
+            set.sort()
+        })
+    }
+}
+
+pub trait SetCombinatorics {
+    /// Returns an iterator over the up-to-24 distinct Tn/TnI forms of the
+    /// pitch-class set, deduplicated by `normal()` form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let pcset: PcSet = vec![0,1,4,6];
+    /// assert_eq!(pcset.orbit().count(), 24);
+    ///
+    /// ```
+    fn orbit(&self) -> Orbit;
+    /// Lazily yields every `k`-element subset of the pitch-class set, in
+    /// combination order, without materializing the full list up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let pcset: PcSet = vec![0,1,2];
+    /// let subsets: Vec<PcSet> = pcset.subsets(2).collect();
+    /// assert_eq!(subsets, vec![vec![0,1], vec![0,2], vec![1,2]]);
+    ///
+    /// ```
+    fn subsets(&self, k: usize) -> Combinations;
+    /// Lazily yields every `k`-element superset of the pitch-class set
+    /// drawn from `universe`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipsi::*;
+    ///
+    /// let pcset: PcSet = vec![0,1];
+    /// let universe: PcSet = vec![0,1,2,3];
+    /// let supersets: Vec<PcSet> = pcset.supersets_within(&universe, 3).collect();
+    /// assert_eq!(supersets, vec![vec![0,1,2], vec![0,1,3]]);
+    ///
+    /// ```
+    fn supersets_within(&self, universe: &Self, k: usize) -> Supersets;
+}
+
+impl SetCombinatorics for PcSet {
+    fn orbit(&self) -> Orbit {
+        let mut forms: Vec<PcSet> = (0..12)
+            .flat_map(|n| vec![self.transpose(n), self.tni(n)])
+            .map(|form| form.normal())
+            .collect();
+        forms.sort();
+        forms.dedup();
+        Orbit { forms, index: 0 }
+    }
+    fn subsets(&self, k: usize) -> Combinations {
+        Combinations::new(self.clone(), k)
+    }
+    fn supersets_within(&self, universe: &PcSet, k: usize) -> Supersets {
+        if k < self.len() {
+            return Supersets { base: self.clone(), combinations: Combinations::new(vec![], 1) };
+        }
+        let remaining: PcSet = universe.iter().filter(|x| !self.contains(x)).cloned().collect();
+        let extra = k - self.len();
+        Supersets { base: self.clone(), combinations: Combinations::new(remaining, extra) }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use Fundamentals;
     use SetOperations;
     use SetAnalysis;
     use PcSet;
+    use ChromaSet;
+    use SetRelations;
+    use SetClass;
+    use ZRelations;
+    use SetCombinatorics;
+    use from_forte;
+    use SET_CLASSES;
 
     #[test]
     fn invert() {
@@ -550,4 +1228,145 @@ mod tests {
         assert_eq!(x.transposition_number(&y), Some(4));
         assert_eq!(x.transpose(4), y);
     }
+    #[test]
+    fn chromaset_transpose() {
+        let c = ChromaSet(0b0000_0000_1110);
+        assert_eq!(c.transpose(4), ChromaSet(0b0000_1110_0000));
+        assert_eq!(c.transpose(0), c);
+    }
+    #[test]
+    fn chromaset_invert() {
+        let c = ChromaSet(0b0000_0000_0110);
+        assert_eq!(c.invert(), ChromaSet(0b1100_0000_0000));
+    }
+    #[test]
+    fn chromaset_complement() {
+        let c = ChromaSet(0b0000_0000_0001);
+        assert_eq!(c.complement(), ChromaSet(0b1111_1111_1110));
+    }
+    #[test]
+    fn chromaset_union_intersection_difference() {
+        let a = ChromaSet(0b0000_0000_0011);
+        let b = ChromaSet(0b0000_0000_0110);
+        assert_eq!(a.union(&b), ChromaSet(0b0000_0000_0111));
+        assert_eq!(a.intersection(&b), ChromaSet(0b0000_0000_0010));
+        assert_eq!(a.difference(&b), ChromaSet(0b0000_0000_0001));
+    }
+    #[test]
+    fn chromaset_conversions() {
+        let pcset: PcSet = vec![1, 2, 3];
+        let chromaset: ChromaSet = pcset.clone().into();
+        assert_eq!(chromaset, ChromaSet(14));
+        let back: PcSet = chromaset.into();
+        assert_eq!(back, vec![1, 2, 3]);
+    }
+    #[test]
+    fn union() {
+        let x: PcSet = vec![0, 1, 2];
+        let y: PcSet = vec![2, 3, 4];
+        assert_eq!(x.union(&y), vec![0, 1, 2, 3, 4]);
+    }
+    #[test]
+    fn intersection() {
+        let x: PcSet = vec![0, 1, 2];
+        let y: PcSet = vec![2, 3, 4];
+        assert_eq!(x.intersection(&y), vec![2]);
+        let z: PcSet = vec![3, 4, 5];
+        assert_eq!(x.intersection(&z), vec![]);
+    }
+    #[test]
+    fn difference() {
+        let x: PcSet = vec![0, 1, 2];
+        let y: PcSet = vec![2, 3, 4];
+        assert_eq!(x.difference(&y), vec![0, 1]);
+    }
+    #[test]
+    fn symmetric_difference() {
+        let x: PcSet = vec![0, 1, 2];
+        let y: PcSet = vec![2, 3, 4];
+        assert_eq!(x.symmetric_difference(&y), vec![0, 1, 3, 4]);
+    }
+    #[test]
+    fn is_subset_superset_disjoint() {
+        let x: PcSet = vec![0, 1];
+        let y: PcSet = vec![0, 1, 2];
+        let z: PcSet = vec![3, 4, 5];
+        assert!(x.is_subset(&y));
+        assert!(y.is_superset(&x));
+        assert!(!x.is_subset(&z));
+        assert!(x.is_disjoint(&z));
+        assert!(!x.is_disjoint(&y));
+    }
+    #[test]
+    fn forte_name() {
+        let empty: PcSet = vec![];
+        assert_eq!(empty.forte_name(), Some("0-1".to_string()));
+        let x: PcSet = vec![0, 1, 2];
+        assert_eq!(x.forte_name(), Some("3-1".to_string()));
+        let y: PcSet = vec![0, 4, 6, 8];
+        assert_eq!(y.forte_name(), Some("4-24".to_string()));
+        let z: PcSet = vec![0, 1, 3, 4, 8];
+        assert_eq!(z.forte_name(), Some("5-Z17".to_string()));
+        let aggregate: PcSet = (0..12).collect();
+        assert_eq!(aggregate.forte_name(), Some("12-1".to_string()));
+        let eight: PcSet = vec![0, 1, 2, 3, 5, 6, 7, 9];
+        assert_eq!(eight.forte_name(), Some("8-Z29".to_string()));
+    }
+    #[test]
+    fn from_forte_roundtrip() {
+        assert_eq!(from_forte("3-1"), Some(vec![0, 1, 2]));
+        assert_eq!(from_forte("4-Z29"), Some(vec![0, 1, 3, 7]));
+        assert_eq!(from_forte("not-a-set-class"), None);
+        let representative = from_forte("8-Z29").unwrap();
+        assert_eq!(representative.forte_name(), Some("8-Z29".to_string()));
+    }
+    #[test]
+    fn set_classes_table_is_self_consistent() {
+        for class in SET_CLASSES {
+            let prime: PcSet = class.prime.to_vec();
+            assert_eq!(prime.forte_name(), Some(class.name.to_string()));
+        }
+    }
+    #[test]
+    fn is_z_related() {
+        let x: PcSet = vec![0, 1, 4, 6];
+        let y: PcSet = vec![0, 1, 3, 7];
+        assert!(x.is_z_related(&y));
+        let z: PcSet = vec![0, 1, 2];
+        assert!(!x.is_z_related(&z));
+        assert!(!x.is_z_related(&x));
+    }
+    #[test]
+    fn is_complement_related() {
+        let x: PcSet = vec![0, 1, 2, 3];
+        let y: PcSet = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        assert!(x.is_complement_related(&y));
+        let z: PcSet = vec![0, 1, 4, 7];
+        assert!(!x.is_complement_related(&z));
+    }
+    #[test]
+    fn orbit() {
+        let x: PcSet = vec![0, 1, 2];
+        assert_eq!(x.orbit().count(), 12);
+        let y: PcSet = vec![0, 3, 6, 9];
+        assert_eq!(y.orbit().count(), 3);
+    }
+    #[test]
+    fn subsets() {
+        let x: PcSet = vec![0, 1, 2];
+        assert_eq!(x.subsets(2).collect::<Vec<PcSet>>(), vec![vec![0, 1], vec![0, 2], vec![1, 2]]);
+        assert_eq!(x.subsets(0).collect::<Vec<PcSet>>(), vec![vec![]]);
+        assert_eq!(x.subsets(4).collect::<Vec<PcSet>>(), Vec::<PcSet>::new());
+    }
+    #[test]
+    fn supersets_within() {
+        let x: PcSet = vec![0, 1];
+        let universe: PcSet = vec![0, 1, 2, 3];
+        assert_eq!(
+            x.supersets_within(&universe, 3).collect::<Vec<PcSet>>(),
+            vec![vec![0, 1, 2], vec![0, 1, 3]]
+        );
+        assert_eq!(x.supersets_within(&universe, 1).collect::<Vec<PcSet>>(), Vec::<PcSet>::new());
+        assert_eq!(x.supersets_within(&universe, 2).collect::<Vec<PcSet>>(), vec![vec![0, 1]]);
+    }
 }